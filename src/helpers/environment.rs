@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// The snarkOS library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkOS library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkOS library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::NodeType;
+use snarkvm::dpc::Network;
+
+use std::{fmt::Debug, marker::PhantomData};
+
+/// The environment configures a node's behavior and the constants it operates under.
+pub trait Environment: 'static + Clone + Debug + Default + Send + Sync {
+    /// The specified network this environment operates on.
+    type Network: Network;
+
+    /// The node type of this environment.
+    const NODE_TYPE: NodeType;
+
+    /// The maximum number of transactions a miner will pack into a single candidate block.
+    const MAXIMUM_BLOCK_TRANSACTIONS: usize = 1024;
+    /// The maximum serialized size, in bytes, of the transactions packed into a candidate block.
+    const MAXIMUM_BLOCK_SIZE: usize = 8 * 1024 * 1024;
+    /// The maximum number of transactions the memory pool will hold before evicting by score.
+    const MAXIMUM_MEMORY_POOL_SIZE: usize = 8192;
+    /// The minimum fee, in gates, by which a replacement must outbid the transaction it evicts.
+    const REPLACE_BY_FEE_BUMP: i64 = 1_000;
+    /// The number of invalid or duplicate submissions a peer may make before its transactions are dropped.
+    const PEER_PENALTY_THRESHOLD: u32 = 16;
+    /// The amount each accumulated penalty biases a peer's subsequent transaction scores downward.
+    const PEER_PENALTY_WEIGHT: i64 = 1_000;
+    /// The interval, in seconds, at which the memory pool is flushed to disk.
+    const MEMORY_POOL_PERSIST_INTERVAL_SECS: u64 = 60;
+}
+
+/// A client node environment.
+#[derive(Clone, Debug, Default)]
+pub struct Client<N: Network>(PhantomData<N>);
+
+impl<N: Network> Environment for Client<N> {
+    type Network = N;
+
+    const NODE_TYPE: NodeType = NodeType::Client;
+}
+
+/// A mining node environment.
+#[derive(Clone, Debug, Default)]
+pub struct Miner<N: Network>(PhantomData<N>);
+
+impl<N: Network> Environment for Miner<N> {
+    type Network = N;
+
+    const NODE_TYPE: NodeType = NodeType::Miner;
+}
+
+/// A sync node environment.
+#[derive(Clone, Debug, Default)]
+pub struct SyncNode<N: Network>(PhantomData<N>);
+
+impl<N: Network> Environment for SyncNode<N> {
+    type Network = N;
+
+    const NODE_TYPE: NodeType = NodeType::Sync;
+}
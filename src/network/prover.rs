@@ -27,11 +27,13 @@ use crate::{
 };
 use snarkvm::dpc::prelude::*;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use rand::thread_rng;
 use rayon::{ThreadPool, ThreadPoolBuilder};
 use std::{
+    collections::{HashMap, HashSet},
     net::SocketAddr,
+    path::{Path, PathBuf},
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc,
@@ -56,10 +58,40 @@ type ProverHandler<N> = mpsc::Receiver<ProverRequest<N>>;
 pub enum ProverRequest<N: Network> {
     /// MemoryPoolClear := (block)
     MemoryPoolClear(Option<Block<N>>),
+    /// RegisterSubscriber := (subscriber)
+    RegisterSubscriber(mpsc::Sender<MemoryPoolEvent<N>>),
     /// UnconfirmedTransaction := (peer_ip, transaction)
     UnconfirmedTransaction(SocketAddr, Transaction<N>),
 }
 
+///
+/// The reason a memory pool event was emitted for a given transaction.
+///
+#[derive(Clone, Debug)]
+pub enum MemoryPoolEventReason {
+    /// The transaction was admitted to the memory pool.
+    Added,
+    /// The transaction was evicted to make room for a higher-scoring one.
+    Evicted,
+    /// The transaction was replaced by a conflicting higher-fee transaction.
+    Replaced,
+    /// The transaction was confirmed by a newly accepted block.
+    Confirmed,
+    /// The transaction was dropped when the pool was cleared wholesale.
+    Cleared,
+}
+
+///
+/// A memory pool event delivered to registered subscribers.
+///
+#[derive(Clone, Debug)]
+pub struct MemoryPoolEvent<N: Network> {
+    /// The id of the transaction the event concerns.
+    pub transaction_id: N::TransactionID,
+    /// The reason the event was emitted.
+    pub reason: MemoryPoolEventReason,
+}
+
 ///
 /// A prover for a specific network on the node server.
 ///
@@ -71,6 +103,12 @@ pub struct Prover<N: Network, E: Environment> {
     prover_router: ProverRouter<N>,
     /// The pool of unconfirmed transactions.
     memory_pool: RwLock<MemoryPool<N>>,
+    /// The number of times each peer has submitted an invalid or duplicate transaction.
+    peer_penalties: RwLock<HashMap<SocketAddr, u32>>,
+    /// The path of the file used to persist the memory pool across restarts.
+    memory_pool_path: PathBuf,
+    /// The set of registered subscribers to memory pool events.
+    subscribers: RwLock<Vec<mpsc::Sender<MemoryPoolEvent<N>>>>,
     /// The status of the node.
     status: Status,
     /// A terminator bit for the prover.
@@ -83,6 +121,23 @@ pub struct Prover<N: Network, E: Environment> {
     ledger_router: LedgerRouter<N>,
 }
 
+///
+/// A guard that flushes the memory pool to disk when it is dropped.
+///
+/// It is held by the persistence task so that, when the node shuts down and the `Tasks` handles are
+/// aborted, the task future is dropped and the pool is persisted one final time.
+///
+struct ShutdownFlush<N: Network, E: Environment> {
+    /// The prover whose memory pool is flushed on drop.
+    prover: Arc<Prover<N, E>>,
+}
+
+impl<N: Network, E: Environment> Drop for ShutdownFlush<N, E> {
+    fn drop(&mut self) {
+        self.prover.save_memory_pool_blocking();
+    }
+}
+
 impl<N: Network, E: Environment> Prover<N, E> {
     /// Initializes a new instance of the prover.
     pub async fn new(
@@ -94,6 +149,7 @@ impl<N: Network, E: Environment> Prover<N, E> {
         peers_router: PeersRouter<N, E>,
         ledger_reader: &LedgerReader<N>,
         ledger_router: LedgerRouter<N>,
+        storage_path: PathBuf,
     ) -> Result<Arc<Self>> {
         // Initialize an mpsc channel for sending requests to the `Prover` struct.
         let (prover_router, mut prover_handler) = mpsc::channel(1024);
@@ -103,11 +159,18 @@ impl<N: Network, E: Environment> Prover<N, E> {
             .num_threads((num_cpus::get() / 8 * 2).max(1))
             .build()?;
 
+        // Restore any previously-persisted memory pool, re-validating every entry against the ledger.
+        let memory_pool_path = storage_path.join("memory_pool.bin");
+        let memory_pool = Self::restore_memory_pool(&memory_pool_path, ledger_reader);
+
         // Initialize the prover.
         let prover = Arc::new(Self {
             miner: Arc::new(pool),
             prover_router,
-            memory_pool: RwLock::new(MemoryPool::new()),
+            memory_pool: RwLock::new(memory_pool),
+            peer_penalties: RwLock::new(HashMap::new()),
+            memory_pool_path,
+            subscribers: RwLock::new(Vec::new()),
             status: status.clone(),
             terminator: terminator.clone(),
             peers_router,
@@ -132,6 +195,26 @@ impl<N: Network, E: Environment> Prover<N, E> {
             let _ = handler.await;
         }
 
+        // Initialize the periodic memory pool persistence task.
+        {
+            let prover = prover.clone();
+            let (router, handler) = oneshot::channel();
+            tasks.append(task::spawn(async move {
+                // Notify the outer function that the task is ready.
+                let _ = router.send(());
+                // When this task's `Tasks` handle is aborted on shutdown, the future is dropped and the
+                // guard flushes the pool one final time — tying the final save to the `Tasks` lifecycle.
+                let _flush_on_shutdown = ShutdownFlush { prover: prover.clone() };
+                loop {
+                    // Periodically flush the memory pool to disk so it survives a restart.
+                    tokio::time::sleep(std::time::Duration::from_secs(E::MEMORY_POOL_PERSIST_INTERVAL_SECS)).await;
+                    prover.save_memory_pool().await;
+                }
+            }));
+            // Wait until the persistence task is ready.
+            let _ = handler.await;
+        }
+
         // Initialize a new instance of the miner.
         if E::NODE_TYPE == NodeType::Miner {
             if let Some(recipient) = miner {
@@ -151,8 +234,16 @@ impl<N: Network, E: Environment> Prover<N, E> {
                             // Prepare the unconfirmed transactions, terminator, and status.
                             let miner = prover.miner.clone();
                             let canon = prover.ledger_reader.clone(); // This is *safe* as the ledger only reads.
-                            let unconfirmed_transactions = prover.memory_pool.read().await.transactions();
+                            // Snapshot the unconfirmed transactions, releasing the pool read guard before scoring.
+                            let candidates = prover.memory_pool.read().await.transactions();
                             let terminator = prover.terminator.clone();
+                            // Greedily pack the highest fee-scoring transactions off the async worker, as the
+                            // scoring pass (sort plus per-transaction serialization) is CPU-bound.
+                            let selection_terminator = terminator.clone();
+                            let unconfirmed_transactions =
+                                task::spawn_blocking(move || Self::select_transactions(candidates, &selection_terminator))
+                                    .await
+                                    .unwrap_or_default();
                             let status = prover.status.clone();
                             let ledger_router = prover.ledger_router.clone();
                             let prover_router = prover.prover_router.clone();
@@ -202,16 +293,101 @@ impl<N: Network, E: Environment> Prover<N, E> {
         self.prover_router.clone()
     }
 
+    ///
+    /// Greedily selects the highest-scoring transactions from `candidates` and packs them into a
+    /// candidate block, bounded by `E::MAXIMUM_BLOCK_TRANSACTIONS` and `E::MAXIMUM_BLOCK_SIZE`.
+    ///
+    /// Transactions are scored primarily by their fee (see [`Self::transaction_score`]); the walk
+    /// skips any transaction whose serial numbers or commitments collide with one already selected,
+    /// so a single block never contains an internal double-spend. The `terminator` is polled on each
+    /// step so packing aborts promptly once a new canonical block arrives.
+    ///
+    fn select_transactions(candidates: Vec<Transaction<N>>, terminator: &AtomicBool) -> Vec<Transaction<N>> {
+        // Score every candidate and walk them from the highest fee downwards.
+        let mut scored: Vec<(i64, Transaction<N>)> =
+            candidates.into_iter().map(|transaction| (Self::transaction_score(&transaction), transaction)).collect();
+        scored.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+        let mut selected = Vec::with_capacity(scored.len());
+        let mut selected_size = 0usize;
+        let mut spent_serial_numbers = HashSet::new();
+        let mut spent_commitments = HashSet::new();
+
+        for (_score, transaction) in scored {
+            // Abort promptly if a new canonical block has arrived.
+            if terminator.load(Ordering::SeqCst) {
+                break;
+            }
+            // Respect the configured maximum transaction count for a block.
+            if selected.len() >= E::MAXIMUM_BLOCK_TRANSACTIONS {
+                break;
+            }
+            // Respect the configured maximum serialized block size.
+            let size = transaction.to_bytes_le().map(|bytes| bytes.len()).unwrap_or(0);
+            if selected_size.saturating_add(size) > E::MAXIMUM_BLOCK_SIZE {
+                continue;
+            }
+            // Skip any transaction that double-spends a record already claimed by this block.
+            let serial_numbers: Vec<_> = transaction.serial_numbers().collect();
+            let commitments: Vec<_> = transaction.commitments().collect();
+            if serial_numbers.iter().any(|serial_number| spent_serial_numbers.contains(serial_number))
+                || commitments.iter().any(|commitment| spent_commitments.contains(commitment))
+            {
+                continue;
+            }
+            spent_serial_numbers.extend(serial_numbers);
+            spent_commitments.extend(commitments);
+            selected_size = selected_size.saturating_add(size);
+            selected.push(transaction);
+        }
+        selected
+    }
+
+    ///
+    /// Returns the selection score for the given transaction.
+    ///
+    /// The score is the transaction fee, recovered as the negation of its value balance; a larger
+    /// fee yields a higher score and thus higher priority during block packing.
+    ///
+    fn transaction_score(transaction: &Transaction<N>) -> i64 {
+        0i64.saturating_sub(transaction.value_balance().0)
+    }
+
     ///
     /// Performs the given `request` to the prover.
     /// All requests must go through this `update`, so that a unified view is preserved.
     ///
-    pub(super) async fn update(&self, request: ProverRequest<N>) {
+    pub(super) async fn update(self: &Arc<Self>, request: ProverRequest<N>) {
         match request {
             ProverRequest::MemoryPoolClear(block) => match block {
-                Some(block) => self.memory_pool.write().await.remove_transactions(block.transactions()),
-                None => *self.memory_pool.write().await = MemoryPool::new(),
+                Some(block) => {
+                    let mut memory_pool = self.memory_pool.write().await;
+                    // Only the transactions this node actually held are confirmed out of its pool.
+                    let pooled: HashSet<_> =
+                        memory_pool.transactions().iter().map(|transaction| transaction.transaction_id()).collect();
+                    let confirmed: Vec<_> = block
+                        .transactions()
+                        .iter()
+                        .map(|transaction| transaction.transaction_id())
+                        .filter(|transaction_id| pooled.contains(transaction_id))
+                        .collect();
+                    // Remove the newly confirmed transactions and notify subscribers.
+                    memory_pool.remove_transactions(block.transactions());
+                    drop(memory_pool);
+                    for transaction_id in confirmed {
+                        self.notify_subscribers(transaction_id, MemoryPoolEventReason::Confirmed).await;
+                    }
+                }
+                None => {
+                    // Capture the cleared transactions so subscribers learn what was dropped.
+                    let cleared = self.memory_pool.read().await.transactions();
+                    *self.memory_pool.write().await = MemoryPool::new();
+                    for transaction in cleared.iter() {
+                        self.notify_subscribers(transaction.transaction_id(), MemoryPoolEventReason::Cleared).await;
+                    }
+                }
             },
+            ProverRequest::RegisterSubscriber(subscriber) => self.subscribers.write().await.push(subscriber),
             ProverRequest::UnconfirmedTransaction(peer_ip, transaction) => {
                 // Ensure the node is not peering.
                 if !self.status.is_peering() {
@@ -223,25 +399,307 @@ impl<N: Network, E: Environment> Prover<N, E> {
     }
 
     ///
-    /// Adds the given unconfirmed transaction to the memory pool.
+    /// Dispatches verification of the given unconfirmed transaction, admitting it to the memory pool
+    /// if it is valid.
     ///
-    async fn add_unconfirmed_transaction(&self, peer_ip: SocketAddr, transaction: Transaction<N>) {
+    /// Verification is performed on a detached task so the serial `update` handler is free to dequeue
+    /// the next request immediately; this keeps multiple transactions verifying concurrently during a
+    /// burst instead of one-at-a-time. Only verified transactions are admitted via
+    /// [`Self::admit_transaction`]; invalid ones feed the per-peer penalty tracker.
+    ///
+    async fn add_unconfirmed_transaction(self: &Arc<Self>, peer_ip: SocketAddr, transaction: Transaction<N>) {
         // Process the unconfirmed transaction.
         trace!("Received unconfirmed transaction {} from {}", transaction.transaction_id(), peer_ip);
         // Ensure the unconfirmed transaction is new.
-        if let Ok(false) = self.ledger_reader.contains_transaction(&transaction.transaction_id()) {
-            debug!("Adding unconfirmed transaction {} to memory pool", transaction.transaction_id());
-            // Attempt to add the unconfirmed transaction to the memory pool.
-            match self.memory_pool.write().await.add_transaction(&transaction) {
-                Ok(()) => {
-                    // Upon success, propagate the unconfirmed transaction to the connected peers.
-                    let request = PeersRequest::MessagePropagate(peer_ip, Message::UnconfirmedTransaction(transaction));
-                    if let Err(error) = self.peers_router.send(request).await {
-                        warn!("[UnconfirmedTransaction] {}", error);
-                    }
+        if let Ok(true) = self.ledger_reader.contains_transaction(&transaction.transaction_id()) {
+            // The transaction is already confirmed on the ledger; penalize the submitting peer.
+            self.penalize_peer(peer_ip).await;
+            return;
+        }
+        // Drop the transaction outright once the peer has crossed the penalty threshold.
+        let penalty = self.peer_penalties.read().await.get(&peer_ip).copied().unwrap_or(0);
+        if penalty >= E::PEER_PENALTY_THRESHOLD {
+            trace!("Dropping transaction from penalized peer {} (penalty {})", peer_ip, penalty);
+            return;
+        }
+        // Verify the transaction's proof and ledger-consistency on a detached task, so that bursts of
+        // incoming transactions verify concurrently without blocking the serial `update` handler.
+        let prover = self.clone();
+        task::spawn(async move {
+            let miner = prover.miner.clone();
+            let ledger_reader = prover.ledger_reader.clone();
+            let candidate = transaction.clone();
+            let verification =
+                task::spawn_blocking(move || miner.install(move || Self::verify_transaction(&ledger_reader, &candidate))).await;
+            match verification {
+                Ok(true) => prover.admit_transaction(peer_ip, transaction, penalty).await,
+                Ok(false) => {
+                    trace!("Dropping invalid transaction {} from {}", transaction.transaction_id(), peer_ip);
+                    prover.penalize_peer(peer_ip).await;
+                }
+                Err(error) => warn!("[UnconfirmedTransaction] verification task failed: {}", error),
+            }
+        });
+    }
+
+    ///
+    /// Admits an already-verified transaction to the memory pool, resolving replace-by-fee conflicts
+    /// and capacity eviction, then propagates it to the connected peers.
+    ///
+    async fn admit_transaction(&self, peer_ip: SocketAddr, transaction: Transaction<N>, penalty: u32) {
+        // Bias the transaction's score downwards by the peer's accumulated penalty.
+        let score = Self::transaction_score(&transaction).saturating_sub((penalty as i64).saturating_mul(E::PEER_PENALTY_WEIGHT));
+
+        debug!("Adding unconfirmed transaction {} to memory pool", transaction.transaction_id());
+        let mut memory_pool = self.memory_pool.write().await;
+        // Resolve any replace-by-fee conflict against transactions already in the pool.
+        let serial_numbers: HashSet<_> = transaction.serial_numbers().collect();
+        let conflicts: Vec<Transaction<N>> = memory_pool
+            .transactions()
+            .into_iter()
+            .filter(|pooled| pooled.serial_numbers().any(|serial_number| serial_numbers.contains(&serial_number)))
+            .collect();
+        if !conflicts.is_empty() {
+            // An identical transaction is already pooled; the submission is a duplicate, so penalize the peer.
+            if conflicts.iter().any(|pooled| pooled.transaction_id() == transaction.transaction_id()) {
+                drop(memory_pool);
+                trace!("Rejecting duplicate transaction {} from {}", transaction.transaction_id(), peer_ip);
+                self.penalize_peer(peer_ip).await;
+                return;
+            }
+            let fee = Self::transaction_score(&transaction);
+            // The newcomer must outbid every conflicting incumbent by at least the configured bump margin.
+            let highest_conflict = conflicts.iter().map(Self::transaction_score).max().unwrap_or(0);
+            if fee < highest_conflict.saturating_add(E::REPLACE_BY_FEE_BUMP) {
+                trace!(
+                    "Rejecting transaction {}; fee does not outbid a conflicting pooled transaction",
+                    transaction.transaction_id()
+                );
+                return;
+            }
+            // The newcomer wins; evict the conflicting incumbents so selection and propagation stay consistent.
+            let replaced_ids: Vec<_> = conflicts.iter().map(|conflict| conflict.transaction_id()).collect();
+            for transaction_id in &replaced_ids {
+                debug!("Replacing transaction {} via replace-by-fee", transaction_id);
+            }
+            memory_pool.remove_transactions(&conflicts);
+            drop(memory_pool);
+            for transaction_id in replaced_ids {
+                self.notify_subscribers(transaction_id, MemoryPoolEventReason::Replaced).await;
+            }
+            memory_pool = self.memory_pool.write().await;
+        }
+        // Enforce the configured memory pool capacity via score-based eviction.
+        let pooled = memory_pool.transactions();
+        if pooled.len() >= E::MAXIMUM_MEMORY_POOL_SIZE {
+            match pooled.iter().min_by_key(|transaction| Self::transaction_score(transaction)) {
+                // Only admit the newcomer if it outscores the current minimum, evicting that entry.
+                Some(lowest) if score > Self::transaction_score(lowest) => {
+                    let lowest = lowest.clone();
+                    let evicted_id = lowest.transaction_id();
+                    memory_pool.remove_transactions(&[lowest]);
+                    drop(memory_pool);
+                    self.notify_subscribers(evicted_id, MemoryPoolEventReason::Evicted).await;
+                    memory_pool = self.memory_pool.write().await;
+                }
+                // Otherwise the pool is full of higher-scoring transactions; reject the newcomer.
+                _ => {
+                    trace!("Rejecting transaction {}; memory pool is full", transaction.transaction_id());
+                    return;
                 }
-                Err(error) => error!("{}", error),
             }
         }
+        // Attempt to add the unconfirmed transaction to the memory pool.
+        match memory_pool.add_transaction(&transaction) {
+            Ok(()) => {
+                drop(memory_pool);
+                // Notify subscribers that a new transaction entered the pool.
+                self.notify_subscribers(transaction.transaction_id(), MemoryPoolEventReason::Added).await;
+                // Upon success, propagate the unconfirmed transaction to the connected peers.
+                let request = PeersRequest::MessagePropagate(peer_ip, Message::UnconfirmedTransaction(transaction));
+                if let Err(error) = self.peers_router.send(request).await {
+                    warn!("[UnconfirmedTransaction] {}", error);
+                }
+            }
+            Err(error) => {
+                drop(memory_pool);
+                error!("{}", error);
+                // A transaction that fails to add is a duplicate or malformed; penalize the peer.
+                self.penalize_peer(peer_ip).await;
+            }
+        }
+    }
+
+    ///
+    /// Loads the persisted memory pool from `path`, re-validating every entry against the current
+    /// ledger and dropping any transaction that is already confirmed or no longer valid.
+    ///
+    /// Returns an empty pool when no store exists yet or the store cannot be read.
+    ///
+    fn restore_memory_pool(path: &Path, ledger_reader: &LedgerReader<N>) -> MemoryPool<N> {
+        let mut memory_pool = MemoryPool::new();
+        if !path.exists() {
+            return memory_pool;
+        }
+        let transactions = match std::fs::read(path) {
+            Ok(bytes) => match Self::deserialize_memory_pool(&bytes) {
+                Ok(transactions) => transactions,
+                Err(error) => {
+                    warn!("Failed to parse memory pool store: {}", error);
+                    return memory_pool;
+                }
+            },
+            Err(error) => {
+                warn!("Failed to read memory pool store: {}", error);
+                return memory_pool;
+            }
+        };
+        for transaction in transactions {
+            // Drop transactions that were confirmed while the node was offline.
+            if let Ok(true) = ledger_reader.contains_transaction(&transaction.transaction_id()) {
+                continue;
+            }
+            // Drop transactions that are no longer valid against the current ledger.
+            if Self::verify_transaction(ledger_reader, &transaction) {
+                if let Err(error) = memory_pool.add_transaction(&transaction) {
+                    trace!("Skipping restored transaction: {}", error);
+                }
+            }
+        }
+        debug!("Restored {} transactions from the memory pool store", memory_pool.transactions().len());
+        memory_pool
+    }
+
+    ///
+    /// Serializes the current memory pool to its backing file under the node's storage directory.
+    ///
+    async fn save_memory_pool(&self) {
+        let transactions = self.memory_pool.read().await.transactions();
+        self.persist_transactions(&transactions);
+    }
+
+    ///
+    /// Synchronously flushes the memory pool to disk for the shutdown path, where awaiting is not
+    /// possible.
+    ///
+    /// Uses a non-blocking `try_read`; at shutdown no other task should hold the lock, but if it is
+    /// contended the flush is skipped rather than risking a deadlock on the runtime thread.
+    ///
+    fn save_memory_pool_blocking(&self) {
+        match self.memory_pool.try_read() {
+            Ok(memory_pool) => self.persist_transactions(&memory_pool.transactions()),
+            Err(_) => warn!("Skipping shutdown flush; memory pool lock is contended"),
+        }
+    }
+
+    ///
+    /// Serializes and writes the given transactions to the memory pool's backing file.
+    ///
+    fn persist_transactions(&self, transactions: &[Transaction<N>]) {
+        match Self::serialize_memory_pool(transactions) {
+            Ok(bytes) => {
+                if let Some(parent) = self.memory_pool_path.parent() {
+                    let _ = std::fs::create_dir_all(parent);
+                }
+                if let Err(error) = std::fs::write(&self.memory_pool_path, bytes) {
+                    warn!("Failed to persist memory pool: {}", error);
+                }
+            }
+            Err(error) => warn!("Failed to serialize memory pool: {}", error),
+        }
+    }
+
+    ///
+    /// Encodes the given transactions as a length-prefixed byte stream for persistence.
+    ///
+    fn serialize_memory_pool(transactions: &[Transaction<N>]) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        (transactions.len() as u32).write_le(&mut bytes)?;
+        for transaction in transactions {
+            let transaction_bytes = transaction.to_bytes_le()?;
+            (transaction_bytes.len() as u32).write_le(&mut bytes)?;
+            bytes.extend_from_slice(&transaction_bytes);
+        }
+        Ok(bytes)
+    }
+
+    ///
+    /// Decodes the length-prefixed byte stream produced by [`Self::serialize_memory_pool`].
+    ///
+    fn deserialize_memory_pool(mut bytes: &[u8]) -> Result<Vec<Transaction<N>>> {
+        let count = u32::read_le(&mut bytes)? as usize;
+        // Reject an implausible header before reserving, so a corrupt store can't request a huge allocation.
+        if count > E::MAXIMUM_MEMORY_POOL_SIZE {
+            bail!("Memory pool store declares {} transactions, exceeding the maximum of {}", count, E::MAXIMUM_MEMORY_POOL_SIZE);
+        }
+        let mut transactions = Vec::with_capacity(count);
+        for _ in 0..count {
+            let length = u32::read_le(&mut bytes)? as usize;
+            // Guard against a truncated or corrupted store, which would otherwise panic in `split_at`.
+            if length > bytes.len() {
+                bail!("Memory pool store is truncated or corrupted");
+            }
+            let (transaction_bytes, rest) = bytes.split_at(length);
+            transactions.push(Transaction::read_le(transaction_bytes)?);
+            bytes = rest;
+        }
+        Ok(transactions)
+    }
+
+    ///
+    /// Returns `true` if the given transaction is valid and consistent with the current ledger.
+    ///
+    /// The transaction's proof must verify, none of its consumed serial numbers may already be
+    /// spent on the ledger, and none of its produced commitments may already exist. When called from
+    /// [`Self::add_unconfirmed_transaction`] this runs on the miner's rayon thread pool so verification
+    /// can proceed in parallel across transactions; [`Self::restore_memory_pool`] calls it directly.
+    ///
+    fn verify_transaction(ledger_reader: &LedgerReader<N>, transaction: &Transaction<N>) -> bool {
+        // The transaction's proof must be valid.
+        if !transaction.verify() {
+            return false;
+        }
+        // None of the consumed serial numbers may already be spent on the ledger.
+        for serial_number in transaction.serial_numbers() {
+            if matches!(ledger_reader.contains_serial_number(&serial_number), Ok(true)) {
+                return false;
+            }
+        }
+        // None of the produced commitments may already exist on the ledger.
+        for commitment in transaction.commitments() {
+            if matches!(ledger_reader.contains_commitment(&commitment), Ok(true)) {
+                return false;
+            }
+        }
+        true
+    }
+
+    ///
+    /// Broadcasts a memory pool event to every registered subscriber.
+    ///
+    /// Subscribers whose receiver has been dropped are pruned so the subscriber list does not grow
+    /// unbounded; a momentarily full channel simply misses the event rather than blocking `update`.
+    ///
+    async fn notify_subscribers(&self, transaction_id: N::TransactionID, reason: MemoryPoolEventReason) {
+        let event = MemoryPoolEvent { transaction_id, reason };
+        let mut subscribers = self.subscribers.write().await;
+        subscribers.retain(|subscriber| {
+            let _ = subscriber.try_send(event.clone());
+            !subscriber.is_closed()
+        });
+    }
+
+    ///
+    /// Increments the penalty counter for the given peer.
+    ///
+    /// The counter is consulted in [`Self::add_unconfirmed_transaction`] to bias a peer's future
+    /// transactions downwards and, once `E::PEER_PENALTY_THRESHOLD` is crossed, to drop them
+    /// outright so a misbehaving peer cannot monopolize memory pool space.
+    ///
+    async fn penalize_peer(&self, peer_ip: SocketAddr) {
+        let mut peer_penalties = self.peer_penalties.write().await;
+        let penalty = peer_penalties.entry(peer_ip).or_insert(0);
+        *penalty = penalty.saturating_add(1);
+        trace!("Penalizing peer {} (penalty now {})", peer_ip, *penalty);
     }
 }
@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// The snarkOS library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkOS library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkOS library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    helpers::{Status, Tasks},
+    Environment,
+    Ledger,
+    Peers,
+    Prover,
+};
+use snarkvm::dpc::prelude::*;
+
+use anyhow::Result;
+use std::{
+    net::SocketAddr,
+    path::PathBuf,
+    sync::{atomic::AtomicBool, Arc},
+};
+use tokio::task::JoinHandle;
+
+///
+/// A node server for a specific network.
+///
+#[derive(Clone)]
+pub struct Server<N: Network, E: Environment> {
+    /// The prover of the node.
+    prover: Arc<Prover<N, E>>,
+}
+
+impl<N: Network, E: Environment> Server<N, E> {
+    /// Initializes a new instance of the server, persisting node state under `storage_path`.
+    pub async fn initialize(
+        tasks: &mut Tasks<JoinHandle<()>>,
+        miner: Option<Address<N>>,
+        local_ip: SocketAddr,
+        status: &Status,
+        terminator: &Arc<AtomicBool>,
+        peers: &Arc<Peers<N, E>>,
+        ledger: &Arc<Ledger<N, E>>,
+        storage_path: PathBuf,
+    ) -> Result<Self> {
+        // Initialize the prover, persisting its memory pool under the node's storage directory.
+        let prover = Prover::new(
+            tasks,
+            miner,
+            local_ip,
+            status,
+            terminator,
+            peers.router(),
+            &ledger.reader(),
+            ledger.router(),
+            storage_path,
+        )
+        .await?;
+
+        Ok(Self { prover })
+    }
+
+    /// Returns the prover of the node.
+    pub fn prover(&self) -> &Arc<Prover<N, E>> {
+        &self.prover
+    }
+}